@@ -2,22 +2,77 @@
 
 use std::{
     env,
-    io,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
     path::PathBuf,
     process::{Child, Command, Stdio},
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-use tauri::{AppHandle, Manager, RunEvent};
+use serde::Serialize;
+use tauri::{AppHandle, CustomMenuItem, Manager, Menu, RunEvent, Submenu};
+
+const DEFAULT_BACKEND_PORT: u16 = 8000;
+const BACKEND_PORT_ENV: &str = "TRADINGAGENTS_BACKEND_PORT";
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(2);
+const RESTART_BACKOFF_START: Duration = Duration::from_secs(1);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(30);
+const SUSTAINED_HEALTHY_RESET: Duration = Duration::from_secs(30);
+const LOG_FILE_NAME: &str = "backend.log";
+const LOG_ROTATE_BYTES: u64 = 5 * 1024 * 1024;
+const LOG_ROTATE_KEEP: u32 = 5;
+const CRASH_LOG_NAME: &str = "tradingagents-crash.log";
+const GRACEFUL_STOP_GRACE: Duration = Duration::from_secs(5);
+const GRACEFUL_STOP_POLL: Duration = Duration::from_millis(100);
+const RESTART_READY_TIMEOUT: Duration = Duration::from_secs(30);
+const HEALTH_FAILURE_THRESHOLD: u32 = 3;
+
+/// Snapshot of backend launch state, kept up to date so the panic hook can
+/// write a self-contained crash report without reproduction steps.
+#[derive(Default)]
+struct CrashContext {
+    python_path: Option<PathBuf>,
+    backend_script: Option<PathBuf>,
+    backend_alive: bool,
+    /// The app log dir resolved once an `AppHandle` exists (the panic hook
+    /// is installed before that, so it can't resolve this itself).
+    crash_log_dir: Option<PathBuf>,
+}
+
+type SharedCrashContext = Arc<Mutex<CrashContext>>;
 
 struct BackendState {
     child: Mutex<Option<Child>>,
+    healthy: AtomicBool,
+    restarting: AtomicBool,
+    shutting_down: AtomicBool,
+    restart_count: AtomicU32,
+    last_exit: Mutex<Option<String>>,
+    log_path: Mutex<Option<PathBuf>>,
+    port: Mutex<Option<u16>>,
+    log_writer: Mutex<Option<Arc<Mutex<RotatingLogWriter>>>>,
+    crash_ctx: Mutex<Option<SharedCrashContext>>,
 }
 
 impl BackendState {
     fn new() -> Self {
         Self {
             child: Mutex::new(None),
+            healthy: AtomicBool::new(false),
+            restarting: AtomicBool::new(false),
+            shutting_down: AtomicBool::new(false),
+            restart_count: AtomicU32::new(0),
+            last_exit: Mutex::new(None),
+            log_path: Mutex::new(None),
+            port: Mutex::new(None),
+            log_writer: Mutex::new(None),
+            crash_ctx: Mutex::new(None),
         }
     }
 
@@ -27,13 +82,342 @@ impl BackendState {
         }
     }
 
+    /// Asks the backend to exit gracefully, escalating to a hard kill if it
+    /// hasn't exited within `GRACEFUL_STOP_GRACE`.
     fn stop(&self) {
         if let Ok(mut guard) = self.child.lock() {
             if let Some(mut child) = guard.take() {
-                let _ = child.kill();
+                graceful_stop(&mut child);
             }
         }
     }
+
+    fn set_healthy(&self, healthy: bool) {
+        self.healthy.store(healthy, Ordering::SeqCst);
+    }
+
+    /// Claims exclusive ownership of spawning the backend. Returns `false`
+    /// if a restart is already in progress, so callers (the `restart_backend`
+    /// command and the menu handler) don't race the supervisor or each other.
+    fn begin_restart(&self) -> bool {
+        self.restarting
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    fn end_restart(&self) {
+        self.restarting.store(false, Ordering::SeqCst);
+    }
+
+    fn is_restarting(&self) -> bool {
+        self.restarting.load(Ordering::SeqCst)
+    }
+
+    /// Marks the backend as intentionally going away for good (app exit or
+    /// relaunch), so the supervisor's death-detection stands down instead of
+    /// respawning a backend behind a window that's already closing.
+    fn begin_shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    fn record_exit(&self, status: String) {
+        if let Ok(mut guard) = self.last_exit.lock() {
+            *guard = Some(status);
+        }
+    }
+
+    fn note_restart(&self) -> u32 {
+        self.restart_count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    fn set_log_path(&self, path: PathBuf) {
+        if let Ok(mut guard) = self.log_path.lock() {
+            *guard = Some(path);
+        }
+    }
+
+    fn set_port(&self, port: u16) {
+        if let Ok(mut guard) = self.port.lock() {
+            *guard = Some(port);
+        }
+    }
+
+    fn port(&self) -> Option<u16> {
+        self.port.lock().ok().and_then(|guard| *guard)
+    }
+
+    fn set_log_writer(&self, writer: Option<Arc<Mutex<RotatingLogWriter>>>) {
+        if let Ok(mut guard) = self.log_writer.lock() {
+            *guard = writer;
+        }
+    }
+
+    fn log_writer(&self) -> Option<Arc<Mutex<RotatingLogWriter>>> {
+        self.log_writer.lock().ok().and_then(|guard| guard.clone())
+    }
+
+    fn set_crash_ctx(&self, ctx: SharedCrashContext) {
+        if let Ok(mut guard) = self.crash_ctx.lock() {
+            *guard = Some(ctx);
+        }
+    }
+
+    fn crash_ctx(&self) -> Option<SharedCrashContext> {
+        self.crash_ctx.lock().ok().and_then(|guard| guard.clone())
+    }
+}
+
+#[derive(Serialize)]
+struct BackendStatus {
+    healthy: bool,
+    restart_count: u32,
+    last_exit: Option<String>,
+}
+
+#[tauri::command]
+fn backend_status(state: tauri::State<BackendState>) -> BackendStatus {
+    BackendStatus {
+        healthy: state.healthy.load(Ordering::SeqCst),
+        restart_count: state.restart_count.load(Ordering::SeqCst),
+        last_exit: state.last_exit.lock().ok().and_then(|guard| guard.clone()),
+    }
+}
+
+#[tauri::command]
+fn backend_log_path(state: tauri::State<BackendState>) -> Option<String> {
+    state
+        .log_path
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .map(|path| path.display().to_string())
+}
+
+#[tauri::command]
+fn get_backend_port(state: tauri::State<BackendState>) -> Option<u16> {
+    state.port()
+}
+
+/// Gracefully stops the backend, respawns it on the same port, and waits
+/// (up to `RESTART_READY_TIMEOUT`) for the new process to report healthy.
+///
+/// Holds `BackendState::restarting` for the duration so the supervisor task
+/// parks instead of racing this with its own down-detection/respawn.
+#[tauri::command]
+async fn restart_backend(app: AppHandle, state: tauri::State<'_, BackendState>) -> Result<(), String> {
+    if !state.begin_restart() {
+        return Err("a backend restart is already in progress".to_string());
+    }
+
+    let result = restart_backend_inner(&app, &state).await;
+    state.end_restart();
+    result
+}
+
+async fn restart_backend_inner(app: &AppHandle, state: &BackendState) -> Result<(), String> {
+    let _ = app.emit_all("backend-restarting", ());
+    state.stop();
+
+    let port = state.port().unwrap_or_else(resolve_backend_port);
+    let child = spawn_backend(app, state.log_writer(), port).map_err(|err| err.to_string())?;
+    state.replace(child);
+    state.note_restart();
+    if let Some(ctx) = state.crash_ctx() {
+        if let Ok(mut guard) = ctx.lock() {
+            guard.backend_alive = true;
+        }
+    }
+
+    let client = reqwest::Client::new();
+    let became_ready = tokio::time::timeout(RESTART_READY_TIMEOUT, async {
+        while !backend_health_ok(&client, port).await {
+            tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+        }
+    })
+    .await
+    .is_ok();
+
+    if !became_ready {
+        return Err(format!(
+            "backend did not report healthy within {RESTART_READY_TIMEOUT:?} after restart"
+        ));
+    }
+
+    state.set_healthy(true);
+    let _ = app.emit_all("backend-ready", ());
+    Ok(())
+}
+
+/// Tears down the backend and relaunches the whole app in place.
+#[tauri::command]
+fn relaunch_app(app: AppHandle, state: tauri::State<BackendState>) {
+    state.begin_shutdown();
+    state.stop();
+    tauri::api::process::restart(&app.env());
+}
+
+const MENU_ID_RESTART_BACKEND: &str = "restart_backend";
+
+fn build_menu() -> Menu {
+    let restart_item = CustomMenuItem::new(MENU_ID_RESTART_BACKEND, "Restart Backend");
+    let backend_menu = Submenu::new("Backend", Menu::new().add_item(restart_item));
+    Menu::new().add_submenu(backend_menu)
+}
+
+/// Append-only log file for captured backend stdio, rotated by size.
+struct RotatingLogWriter {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl RotatingLogWriter {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        Ok(Self { path, file, size })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if self.size >= LOG_ROTATE_BYTES {
+            self.rotate();
+        }
+
+        let formatted = format!("{line}\n");
+        if self.file.write_all(formatted.as_bytes()).is_ok() {
+            self.size += formatted.len() as u64;
+        }
+    }
+
+    fn rotate(&mut self) {
+        for index in (1..LOG_ROTATE_KEEP).rev() {
+            let from = self.rotated_path(index);
+            let to = self.rotated_path(index + 1);
+            if from.exists() {
+                let _ = fs::rename(&from, &to);
+            }
+        }
+        let _ = fs::rename(&self.path, self.rotated_path(1));
+
+        match OpenOptions::new().create(true).append(true).open(&self.path) {
+            Ok(file) => {
+                self.file = file;
+                self.size = 0;
+            }
+            Err(err) => println!("warning: failed to reopen backend log after rotation: {err}"),
+        }
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        PathBuf::from(format!("{}.{}", self.path.display(), index))
+    }
+}
+
+fn resolve_log_dir(app: &AppHandle) -> PathBuf {
+    if let Ok(explicit) = env::var("TRADINGAGENTS_LOG_DIR") {
+        return PathBuf::from(explicit);
+    }
+
+    app.path_resolver()
+        .app_log_dir()
+        .unwrap_or_else(|| env::current_dir().unwrap_or_else(|_| PathBuf::from(".")))
+}
+
+fn open_log_writer(app: &AppHandle) -> io::Result<RotatingLogWriter> {
+    let dir = resolve_log_dir(app);
+    fs::create_dir_all(&dir)?;
+    RotatingLogWriter::open(dir.join(LOG_FILE_NAME))
+}
+
+/// Falls back to the `TRADINGAGENTS_LOG_DIR` override or cwd only for
+/// panics that happen before `CrashContext::crash_log_dir` is populated
+/// (i.e. before an `AppHandle` exists); otherwise reuses the app log dir.
+fn crash_log_dir(resolved: Option<PathBuf>) -> PathBuf {
+    resolved.unwrap_or_else(|| {
+        if let Ok(explicit) = env::var("TRADINGAGENTS_LOG_DIR") {
+            PathBuf::from(explicit)
+        } else {
+            env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
+        }
+    })
+}
+
+/// Installs a panic hook that appends a self-contained crash report
+/// (message, backtrace, and resolved backend launch state) to
+/// `tradingagents-crash.log` before falling through to the default hook.
+fn install_panic_hook(crash_ctx: SharedCrashContext) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let (python_path, backend_script, backend_alive, crash_dir) = crash_ctx
+            .lock()
+            .map(|guard| {
+                (
+                    guard.python_path.clone(),
+                    guard.backend_script.clone(),
+                    guard.backend_alive,
+                    guard.crash_log_dir.clone(),
+                )
+            })
+            .unwrap_or((None, None, false, None));
+        let backtrace = std::backtrace::Backtrace::force_capture();
+
+        let report = format!(
+            "[{}] {info}\n  python: {}\n  backend_script: {}\n  backend_alive: {backend_alive}\n  backtrace:\n{backtrace}\n",
+            log_timestamp(),
+            python_path
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "<unresolved>".to_string()),
+            backend_script
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "<unresolved>".to_string()),
+        );
+
+        let dir = crash_log_dir(crash_dir);
+        if fs::create_dir_all(&dir).is_ok() {
+            if let Ok(mut file) = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join(CRASH_LOG_NAME))
+            {
+                let _ = file.write_all(report.as_bytes());
+            }
+        }
+
+        log::error!("{report}");
+        default_hook(info);
+    }));
+}
+
+fn log_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+fn drain_pipe<R>(mut reader: R, writer: Arc<Mutex<RotatingLogWriter>>, tag: &'static str)
+where
+    R: BufRead + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let trimmed = line.trim_end_matches(['\r', '\n']);
+                    if let Ok(mut guard) = writer.lock() {
+                        guard.write_line(&format!("[{}] [{tag}] {trimmed}", log_timestamp()));
+                    }
+                }
+            }
+        }
+    });
 }
 
 fn preferred_python() -> PathBuf {
@@ -98,40 +482,385 @@ fn locate_backend_script(app: &AppHandle) -> Option<PathBuf> {
     None
 }
 
-fn spawn_backend(app: &AppHandle) -> tauri::Result<Child> {
+fn explicit_backend_port() -> Option<u16> {
+    env::var(BACKEND_PORT_ENV).ok().and_then(|explicit| explicit.parse::<u16>().ok())
+}
+
+/// Picks the port the backend should bind to: an explicit override if set,
+/// otherwise an OS-assigned free ephemeral port.
+///
+/// Binding to port 0 and then dropping the listener before the child binds
+/// it is inherently TOCTOU: another process can grab the same port in that
+/// gap. We accept that window here and mitigate it for the concurrent-
+/// instance case by re-resolving a fresh port (see `spawn_supervisor`)
+/// whenever the child dies before ever reporting healthy.
+fn resolve_backend_port() -> u16 {
+    explicit_backend_port().unwrap_or_else(|| {
+        std::net::TcpListener::bind(("127.0.0.1", 0))
+            .and_then(|listener| listener.local_addr())
+            .map(|addr| addr.port())
+            .unwrap_or(DEFAULT_BACKEND_PORT)
+    })
+}
+
+fn spawn_backend(
+    app: &AppHandle,
+    log_writer: Option<Arc<Mutex<RotatingLogWriter>>>,
+    port: u16,
+) -> tauri::Result<Child> {
     let script_path = locate_backend_script(app).ok_or_else(|| {
         io::Error::new(io::ErrorKind::NotFound, "run_backend.py not found")
     })?;
 
     let python = preferred_python();
 
-    Command::new(python)
+    let mut command = Command::new(python);
+    command
         .arg(script_path)
-        .stdin(Stdio::null())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-        .map_err(tauri::Error::from)
+        .env(BACKEND_PORT_ENV, port.to_string())
+        .stdin(Stdio::null());
+
+    if log_writer.is_some() {
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    } else {
+        command.stdout(Stdio::null()).stderr(Stdio::null());
+    }
+
+    let mut child = command.spawn().map_err(tauri::Error::from)?;
+
+    if let Some(writer) = log_writer {
+        if let Some(stdout) = child.stdout.take() {
+            drain_pipe(BufReader::new(stdout), writer.clone(), "backend-stdout");
+        }
+        if let Some(stderr) = child.stderr.take() {
+            drain_pipe(BufReader::new(stderr), writer, "backend-stderr");
+        }
+    }
+
+    Ok(child)
+}
+
+/// Requests a cooperative exit (SIGTERM on Unix, `taskkill` soft stop on
+/// Windows) and falls back to a hard kill once the grace period lapses. If
+/// the soft request can't be delivered, or `try_wait` fails, the hard kill
+/// happens right away.
+fn graceful_stop(child: &mut Child) {
+    if request_graceful_exit(child) {
+        let deadline = Instant::now() + GRACEFUL_STOP_GRACE;
+        while Instant::now() < deadline {
+            match child.try_wait() {
+                Ok(Some(_)) => return,
+                Ok(None) => thread::sleep(GRACEFUL_STOP_POLL),
+                Err(_) => break,
+            }
+        }
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(unix)]
+fn request_graceful_exit(child: &Child) -> bool {
+    // SAFETY: `child.id()` is a valid pid for as long as the child handle is alive.
+    unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGTERM) == 0 }
+}
+
+#[cfg(windows)]
+fn request_graceful_exit(child: &Child) -> bool {
+    Command::new("taskkill")
+        .args(["/PID", &child.id().to_string()])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+fn backend_health_url(port: u16) -> String {
+    format!("http://127.0.0.1:{port}/health")
+}
+
+async fn backend_health_ok(client: &reqwest::Client, port: u16) -> bool {
+    client
+        .get(backend_health_url(port))
+        .send()
+        .await
+        .map(|response| response.status().is_success())
+        .unwrap_or(false)
+}
+
+/// Outcome of waiting for the backend to become healthy.
+enum ReadinessOutcome {
+    Ready,
+    Died(String),
+    ShuttingDown,
+}
+
+/// Checks whether the supervised child has exited, taking it out of
+/// `BackendState` if so. Returns `None` while it's still running.
+fn poll_child_exit(state: &BackendState) -> Option<String> {
+    match state.child.lock() {
+        Ok(mut guard) => match guard.as_mut() {
+            Some(child) => match child.try_wait() {
+                Ok(Some(status)) => {
+                    guard.take();
+                    Some(status.to_string())
+                }
+                Ok(None) => None,
+                Err(err) => Some(format!("wait failed: {err}")),
+            },
+            None => Some("backend not running".to_string()),
+        },
+        Err(_) => Some("backend state poisoned".to_string()),
+    }
+}
+
+/// Polls `/health` until it responds, but also watches for the child dying
+/// before it ever comes up (e.g. a bad config causing an immediate crash).
+async fn wait_until_ready_or_dead(
+    client: &reqwest::Client,
+    port: u16,
+    state: &BackendState,
+) -> ReadinessOutcome {
+    loop {
+        // The app is exiting/relaunching for good; don't fight the teardown
+        // by declaring the backend down and trying to spawn a replacement.
+        if state.is_shutting_down() {
+            return ReadinessOutcome::ShuttingDown;
+        }
+
+        // `restart_backend`/the menu action own spawning while this is set;
+        // stand down so we don't race them into a double-spawn.
+        if state.is_restarting() {
+            tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+            continue;
+        }
+
+        if backend_health_ok(client, port).await {
+            return ReadinessOutcome::Ready;
+        }
+
+        if let Some(status) = poll_child_exit(state) {
+            return ReadinessOutcome::Died(status);
+        }
+
+        tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+    }
+}
+
+/// Polls readiness, emits heartbeat/down events, and respawns the backend
+/// with exponential backoff whenever it dies.
+fn spawn_supervisor(
+    app: AppHandle,
+    log_writer: Option<Arc<Mutex<RotatingLogWriter>>>,
+    crash_ctx: SharedCrashContext,
+    port: u16,
+) {
+    tauri::async_runtime::spawn(async move {
+        let client = reqwest::Client::new();
+        let state = app.state::<BackendState>();
+        let mut backoff = RESTART_BACKOFF_START;
+        let mut port = port;
+
+        loop {
+            if state.is_shutting_down() {
+                return;
+            }
+
+            loop {
+                match wait_until_ready_or_dead(&client, port, &state).await {
+                    ReadinessOutcome::Ready => break,
+                    ReadinessOutcome::ShuttingDown => return,
+                    ReadinessOutcome::Died(status) => {
+                        state.set_healthy(false);
+                        state.record_exit(status);
+                        if let Ok(mut guard) = crash_ctx.lock() {
+                            guard.backend_alive = false;
+                        }
+                        let _ = app.emit_all("backend-down", ());
+
+                        tokio::time::sleep(backoff).await;
+
+                        if state.is_shutting_down() {
+                            return;
+                        }
+
+                        // The backend died before ever reporting healthy,
+                        // which is the common symptom of losing the TOCTOU
+                        // port race against another instance. Pick a fresh
+                        // port before retrying unless the user pinned one.
+                        if explicit_backend_port().is_none() {
+                            port = resolve_backend_port();
+                            state.set_port(port);
+                            let _ = app.emit_all("backend-port", port);
+                        }
+
+                        match spawn_backend(&app, log_writer.clone(), port) {
+                            Ok(child) => {
+                                state.replace(child);
+                                state.note_restart();
+                            }
+                            Err(err) => {
+                                println!("warning: failed to respawn FastAPI backend: {err}");
+                            }
+                        }
+                        backoff = (backoff * 2).min(RESTART_BACKOFF_MAX);
+                    }
+                }
+            }
+
+            state.set_healthy(true);
+            if let Ok(mut guard) = crash_ctx.lock() {
+                guard.backend_alive = true;
+            }
+            let _ = app.emit_all("backend-ready", ());
+            let healthy_since = tokio::time::Instant::now();
+            let mut consecutive_health_failures: u32 = 0;
+
+            let died_with = loop {
+                tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+
+                if state.is_shutting_down() {
+                    return;
+                }
+
+                if state.is_restarting() {
+                    continue;
+                }
+
+                if let Some(status) = poll_child_exit(&state) {
+                    break status;
+                }
+
+                if backend_health_ok(&client, port).await {
+                    consecutive_health_failures = 0;
+                    if healthy_since.elapsed() >= SUSTAINED_HEALTHY_RESET {
+                        backoff = RESTART_BACKOFF_START;
+                    }
+                    let _ = app.emit_all("backend-heartbeat", ());
+                } else {
+                    consecutive_health_failures += 1;
+                    // Tolerate transient hiccups; only declare the backend
+                    // down once several probes in a row have failed.
+                    if consecutive_health_failures >= HEALTH_FAILURE_THRESHOLD {
+                        break format!(
+                            "{HEALTH_FAILURE_THRESHOLD} consecutive health checks failed"
+                        );
+                    }
+                }
+            };
+
+            if state.is_shutting_down() {
+                return;
+            }
+
+            state.set_healthy(false);
+            state.record_exit(died_with);
+            if let Ok(mut guard) = crash_ctx.lock() {
+                guard.backend_alive = false;
+            }
+            let _ = app.emit_all("backend-down", ());
+
+            // The child may still be alive here (e.g. stuck and failing
+            // health checks rather than exited) — stop it before spawning a
+            // replacement so it doesn't leak as an orphan on the same port.
+            state.stop();
+
+            tokio::time::sleep(backoff).await;
+
+            if state.is_shutting_down() {
+                return;
+            }
+
+            match spawn_backend(&app, log_writer.clone(), port) {
+                Ok(child) => {
+                    state.replace(child);
+                    state.note_restart();
+                }
+                Err(err) => {
+                    println!("warning: failed to respawn FastAPI backend: {err}");
+                }
+            }
+            backoff = (backoff * 2).min(RESTART_BACKOFF_MAX);
+        }
+    });
 }
 
 fn main() {
+    let crash_ctx: SharedCrashContext = Arc::new(Mutex::new(CrashContext::default()));
+    install_panic_hook(crash_ctx.clone());
+
     tauri::Builder::default()
         .manage(BackendState::new())
-        .setup(|app| {
+        .invoke_handler(tauri::generate_handler![
+            backend_status,
+            backend_log_path,
+            get_backend_port,
+            restart_backend,
+            relaunch_app
+        ])
+        .setup(move |app| {
             let backend_state = app.state::<BackendState>();
             let app_handle = app.handle();
-            if let Ok(child) = spawn_backend(&app_handle) {
+
+            backend_state.set_crash_ctx(crash_ctx.clone());
+
+            let python_path = preferred_python();
+            let backend_script = locate_backend_script(&app_handle);
+            if let Ok(mut guard) = crash_ctx.lock() {
+                guard.python_path = Some(python_path);
+                guard.backend_script = backend_script;
+            }
+
+            let log_writer = match open_log_writer(&app_handle) {
+                Ok(writer) => {
+                    backend_state.set_log_path(writer.path.clone());
+                    if let (Ok(mut guard), Some(dir)) =
+                        (crash_ctx.lock(), writer.path.parent())
+                    {
+                        guard.crash_log_dir = Some(dir.to_path_buf());
+                    }
+                    Some(Arc::new(Mutex::new(writer)))
+                }
+                Err(err) => {
+                    println!("warning: failed to open backend log file: {err}");
+                    None
+                }
+            };
+            backend_state.set_log_writer(log_writer.clone());
+
+            let port = resolve_backend_port();
+            backend_state.set_port(port);
+            let _ = app_handle.emit_all("backend-port", port);
+
+            if let Ok(child) = spawn_backend(&app_handle, log_writer.clone(), port) {
                 backend_state.replace(child);
+                if let Ok(mut guard) = crash_ctx.lock() {
+                    guard.backend_alive = true;
+                }
+                spawn_supervisor(app_handle, log_writer, crash_ctx.clone(), port);
             } else {
                 println!("warning: failed to spawn FastAPI backend. Launch manually with `python run_backend.py --reload`.");
             }
             Ok(())
         })
+        .menu(build_menu())
+        .on_menu_event(|event| {
+            if event.menu_item_id() == MENU_ID_RESTART_BACKEND {
+                let app_handle = event.window().app_handle();
+                tauri::async_runtime::spawn(async move {
+                    let state = app_handle.state::<BackendState>();
+                    if let Err(err) = restart_backend(app_handle.clone(), state).await {
+                        println!("warning: menu-triggered backend restart failed: {err}");
+                    }
+                });
+            }
+        })
         .build(tauri::generate_context!())
         .expect("error while running tauri application")
         .run(|app_handle, event| {
             if matches!(event, RunEvent::Exit | RunEvent::ExitRequested { .. }) {
                 if let Some(state) = app_handle.try_state::<BackendState>() {
+                    state.begin_shutdown();
                     state.stop();
                 }
             }